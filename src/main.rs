@@ -1,92 +1,344 @@
 use nannou::prelude::*;
+use rand_distr::{Distribution, Normal};
 use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 use std::f32::consts::PI;
+use std::f32::consts::TAU;
 
 const BALL_COUNT: usize = 5;
 const BALL_SPEED: f32 = 3.0;
+const BALL_SPEED_STD_DEV: f32 = 0.8;
+const BALL_RADIUS: f32 = 20.0;
+const BALL_RADIUS_STD_DEV: f32 = 6.0;
+const BALL_RADIUS_MIN: f32 = 8.0;
+const BALL_RADIUS_MAX: f32 = 40.0;
+const BALL_MASS: f32 = 1.0;
+const RESTITUTION: f32 = 0.9;
+
+// Uniform grid cell size for the collision broadphase; roughly twice the
+// largest possible ball radius so a ball never spans more than its 4 home cells.
+const CELL_SIZE: f32 = BALL_RADIUS_MAX * 2.0;
+
+// How fast a ball's position in its palette advances each frame.
+const PALETTE_PHASE_SPEED: f32 = 0.003;
+
+// Number of concentric discs used to approximate a radial glow falloff.
+const GLOW_RINGS: u32 = 8;
+
+// Settling-mode physics: downward acceleration, velocity-proportional air
+// drag, the fraction of speed kept on a wall bounce, and how much of the
+// tangential velocity converts to spin (and back) on wall contact.
+const GRAVITY: f32 = -0.15;
+const DRAG: f32 = 0.0015;
+const WALL_RESTITUTION: f32 = 0.6;
+const SPIN_TRANSFER: f32 = 0.3;
+
+/// Selects whether balls move and bounce with frictionless, perfectly
+/// elastic motion (the original screensaver behaviour) or settle under gravity, drag,
+/// and lossy wall bounces with visible spin.
+#[derive(Clone, Copy, PartialEq)]
+enum PhysicsMode {
+    Frictionless,
+    Settling,
+}
+
+// Pong mode: paddle dimensions/speed, how far from the left/right edge they
+// sit, and the steepest angle a paddle can deflect the puck at.
+const PADDLE_WIDTH: f32 = 15.0;
+const PADDLE_HEIGHT: f32 = 100.0;
+const PADDLE_SPEED: f32 = 6.0;
+const PADDLE_MARGIN: f32 = 30.0;
+const MAX_FIRE_ANGLE: f32 = PI / 3.0;
+
+/// Selects whether the window runs as the free-bouncing screensaver or as a
+/// two-player pong match with a single puck.
+#[derive(Clone, Copy, PartialEq)]
+enum GameMode {
+    FreeBounce,
+    Pong,
+}
+
+/// An axis-aligned paddle anchored to the left or right edge; only its `y`
+/// position moves.
+struct Paddle {
+    position: Point2,
+    half_width: f32,
+    half_height: f32,
+}
+
+impl Paddle {
+    fn new(x: f32) -> Self {
+        Paddle {
+            position: pt2(x, 0.0),
+            half_width: PADDLE_WIDTH / 2.0,
+            half_height: PADDLE_HEIGHT / 2.0,
+        }
+    }
+
+    fn move_by(&mut self, dy: f32, win_rect: &Rect) {
+        let min_y = win_rect.bottom() + self.half_height;
+        let max_y = win_rect.top() - self.half_height;
+        self.position.y = (self.position.y + dy).clamp(min_y, max_y);
+    }
+}
+
+/// An ordered list of `(position, color)` stops in `[0, 1]`, linearly
+/// interpolated to produce a coherent color cycle instead of independent
+/// per-channel noise.
+struct Palette {
+    stops: Vec<(f32, Rgb)>,
+}
+
+impl Palette {
+    /// Black -> red -> orange -> yellow -> white.
+    fn fire() -> Self {
+        Palette {
+            stops: vec![
+                (0.0, rgb(0.0, 0.0, 0.0)),
+                (0.3, rgb(0.8, 0.0, 0.0)),
+                (0.6, rgb(1.0, 0.5, 0.0)),
+                (0.85, rgb(1.0, 1.0, 0.0)),
+                (1.0, rgb(1.0, 1.0, 1.0)),
+            ],
+        }
+    }
+
+    /// Navy -> teal -> cyan.
+    fn ocean() -> Self {
+        Palette {
+            stops: vec![
+                (0.0, rgb(0.0, 0.0, 0.5)),
+                (0.5, rgb(0.0, 0.5, 0.5)),
+                (1.0, rgb(0.0, 1.0, 1.0)),
+            ],
+        }
+    }
+
+    /// Linearly interpolates the color at `t` between its two surrounding
+    /// stops. `t` is expected to be in `[0, 1]`.
+    fn sample(&self, t: f32) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+
+        for window in self.stops.windows(2) {
+            let (pos_a, color_a) = window[0];
+            let (pos_b, color_b) = window[1];
+
+            if t >= pos_a && t <= pos_b {
+                let span = pos_b - pos_a;
+                let local_t = if span > 0.0 { (t - pos_a) / span } else { 0.0 };
+                return rgb(
+                    lerp(color_a.red, color_b.red, local_t),
+                    lerp(color_a.green, color_b.green, local_t),
+                    lerp(color_a.blue, color_b.blue, local_t),
+                );
+            }
+        }
+
+        self.stops
+            .last()
+            .map(|&(_, color)| color)
+            .unwrap_or(rgb(0.0, 0.0, 0.0))
+    }
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Mass scales with area (`mass ∝ radius²`) so bigger balls push smaller
+/// ones around in `Ball::collide`, normalized to `BALL_MASS` at `BALL_RADIUS`.
+fn mass_for_radius(radius: f32) -> f32 {
+    BALL_MASS * (radius / BALL_RADIUS).powi(2)
+}
 
 struct Ball {
     position: Point2,
     velocity: Vec2,
+    radius: f32,
+    mass: f32,
     color: Rgb,
-    color_change_speed: Rgb,
-    color_change_direction: Rgb,
+    palette_phase: f32,
+    glow_radius: f32,
+    glow_intensity: f32,
+    angular_velocity: f32,
+    spin_angle: f32,
 }
 
 impl Ball {
-    fn new(
-        position: Point2,
-        velocity: Vec2,
-        color: Rgb,
-        color_change_speed: Rgb,
-        color_change_direction: Rgb,
-    ) -> Self {
+    fn new(position: Point2, velocity: Vec2, radius: f32, mass: f32, palette_phase: f32) -> Self {
         Ball {
             position,
             velocity,
-            color,
-            color_change_speed,
-            color_change_direction,
+            radius,
+            mass,
+            color: rgb(0.0, 0.0, 0.0),
+            palette_phase,
+            glow_radius: radius * 1.5,
+            glow_intensity: 0.2,
+            angular_velocity: 0.0,
+            spin_angle: 0.0,
         }
     }
 
-    fn update(&mut self, win_rect: &Rect) {
+    fn update(&mut self, win_rect: &Rect, palette: &Palette, physics: PhysicsMode) {
+        if physics == PhysicsMode::Settling {
+            self.velocity.y += GRAVITY;
+            self.velocity *= 1.0 - DRAG;
+        }
+
         self.position += self.velocity;
 
-        if self.position.x < win_rect.left() || self.position.x > win_rect.right() {
-            self.velocity.x = -self.velocity.x;
+        if self.position.x - self.radius < win_rect.left()
+            || self.position.x + self.radius > win_rect.right()
+        {
+            self.bounce_x(physics);
         }
 
-        if self.position.y < win_rect.bottom() || self.position.y > win_rect.top() {
-            self.velocity.y = -self.velocity.y;
+        if self.position.y - self.radius < win_rect.bottom()
+            || self.position.y + self.radius > win_rect.top()
+        {
+            self.bounce_y(physics);
         }
-        // Update the color
-        self.color.red += self.color_change_speed.red * self.color_change_direction.red;
-        self.color.green += self.color_change_speed.green * self.color_change_direction.green;
-        self.color.blue += self.color_change_speed.blue * self.color_change_direction.blue;
 
-        // Reverse color change direction if the color component reaches the minimum or maximum value
-        self.color_change_direction.red *= if self.color.red <= 0.0 || self.color.red >= 1.0 {
-            -1.0
-        } else {
-            1.0
-        };
-        self.color_change_direction.green *= if self.color.green <= 0.0 || self.color.green >= 1.0 {
-            -1.0
-        } else {
-            1.0
-        };
-        self.color_change_direction.blue *= if self.color.blue <= 0.0 || self.color.blue >= 1.0 {
-            -1.0
-        } else {
-            1.0
-        };
+        self.spin_angle = (self.spin_angle + self.angular_velocity) % TAU;
+
+        self.palette_phase = (self.palette_phase + PALETTE_PHASE_SPEED) % 1.0;
+        self.color = palette.sample(self.palette_phase);
+
+        // Faster and bigger balls glow further and brighter.
+        let speed = self.velocity.length();
+        self.glow_radius = self.radius * (1.5 + speed * 0.1);
+        self.glow_intensity = (0.2 + speed * 0.03).min(0.6);
+    }
+
+    /// Reflects off a left/right wall. In settling mode, tangential
+    /// (vertical) velocity and spin relax toward rolling-without-slipping
+    /// by `SPIN_TRANSFER` of their difference, which only ever redistributes
+    /// energy between the two rather than adding to it.
+    fn bounce_x(&mut self, physics: PhysicsMode) {
+        match physics {
+            PhysicsMode::Frictionless => self.velocity.x = -self.velocity.x,
+            PhysicsMode::Settling => {
+                let spin_velocity = self.angular_velocity * self.radius;
+                let exchange = SPIN_TRANSFER * (self.velocity.y - spin_velocity);
+                self.velocity.y -= exchange;
+                self.angular_velocity += exchange / self.radius;
+
+                self.velocity.x = -self.velocity.x * WALL_RESTITUTION;
+            }
+        }
+    }
+
+    /// Reflects off a top/bottom wall. See `bounce_x` for the spin exchange.
+    fn bounce_y(&mut self, physics: PhysicsMode) {
+        match physics {
+            PhysicsMode::Frictionless => self.velocity.y = -self.velocity.y,
+            PhysicsMode::Settling => {
+                let spin_velocity = self.angular_velocity * self.radius;
+                let exchange = SPIN_TRANSFER * (self.velocity.x - spin_velocity);
+                self.velocity.x -= exchange;
+                self.angular_velocity += exchange / self.radius;
+
+                self.velocity.y = -self.velocity.y * WALL_RESTITUTION;
+            }
+        }
     }
 
     fn collide(&mut self, other: &mut Ball) {
         let distance = self.position.distance(other.position);
-        let radii_sum = 20.0 * 2.0; // Assuming balls have the same radius, which is 20.0
+        let radii_sum = self.radius + other.radius;
 
         if distance < radii_sum {
-            let collision_vector = self.position - other.position;
-            let normal = collision_vector.normalize();
+            let normal = (self.position - other.position).normalize();
 
-            // Calculate the response velocities
-            let self_velocity = self.velocity.dot(normal) * normal;
-            let other_velocity = other.velocity.dot(normal) * normal;
+            let rv = self.velocity - other.velocity;
+            let vel_along_normal = rv.dot(normal);
 
-            // Swap the velocities
-            self.velocity += other_velocity - self_velocity;
-            other.velocity += self_velocity - other_velocity;
+            // Already separating; nothing to resolve.
+            if vel_along_normal > 0.0 {
+                return;
+            }
+
+            let inv_mass_self = 1.0 / self.mass;
+            let inv_mass_other = 1.0 / other.mass;
+            let j = -(1.0 + RESTITUTION) * vel_along_normal / (inv_mass_self + inv_mass_other);
 
-            // Reposition the balls to avoid overlapping
+            self.velocity += normal * (j * inv_mass_self);
+            other.velocity -= normal * (j * inv_mass_other);
+
+            // Reposition the balls to avoid overlapping, split by inverse mass.
             let overlap = radii_sum - distance;
-            let correction = normal * (overlap / 2.0);
-            self.position += correction;
-            other.position -= correction;
+            let correction = normal * (overlap / (inv_mass_self + inv_mass_other));
+            self.position += correction * inv_mass_self;
+            other.position -= correction * inv_mass_other;
+        }
+    }
+}
+
+/// Buckets ball indices into a uniform grid so collision checks only
+/// consider nearby balls instead of every pair in the scene.
+struct SpatialHash {
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl SpatialHash {
+    fn new() -> Self {
+        SpatialHash {
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell_of(point: Point2) -> (i32, i32) {
+        (
+            (point.x / CELL_SIZE).floor() as i32,
+            (point.y / CELL_SIZE).floor() as i32,
+        )
+    }
+
+    /// Inserts a ball into every cell its bounding circle overlaps (up to
+    /// the 4 cells surrounding its center when it straddles a boundary).
+    fn insert(&mut self, index: usize, ball: &Ball) {
+        let min = Self::cell_of(pt2(
+            ball.position.x - ball.radius,
+            ball.position.y - ball.radius,
+        ));
+        let max = Self::cell_of(pt2(
+            ball.position.x + ball.radius,
+            ball.position.y + ball.radius,
+        ));
+
+        for cx in min.0..=max.0 {
+            for cy in min.1..=max.1 {
+                self.cells
+                    .entry((cx, cy))
+                    .or_insert_with(Vec::new)
+                    .push(index);
+            }
         }
     }
 
+    /// Calls `f` with each unordered pair of ball indices that might be
+    /// colliding, each pair visited exactly once.
+    fn for_each_candidate_pair(&self, mut f: impl FnMut(usize, usize)) {
+        const NEIGHBORS: [(i32, i32); 5] = [(0, 0), (1, 0), (0, 1), (1, 1), (1, -1)];
+
+        // A ball can sit in several cells, so the same pair can turn up more than once.
+        let mut seen = HashSet::new();
+
+        for (&(cx, cy), home) in &self.cells {
+            for &(dx, dy) in &NEIGHBORS {
+                if let Some(neighbor) = self.cells.get(&(cx + dx, cy + dy)) {
+                    for &i in home {
+                        for &j in neighbor {
+                            if i < j && seen.insert((i, j)) {
+                                f(i, j);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 fn main() {
@@ -94,7 +346,12 @@ fn main() {
 }
 
 struct Model {
+    mode: GameMode,
     balls: Vec<Ball>,
+    palette: Palette,
+    physics: PhysicsMode,
+    paddles: Option<(Paddle, Paddle)>,
+    score: (u32, u32),
 }
 
 fn model(app: &App) -> Model {
@@ -105,75 +362,252 @@ fn model(app: &App) -> Model {
         .build()
         .unwrap();
 
-    let balls = (0..BALL_COUNT)
-        .map(|_| {
-            let position = random_range2(-400.0, 400.0, -300.0, 300.0);
-            let angle = random_range(0.0, 2.0 * PI);
-            let velocity = Vec2::new(angle.cos() * BALL_SPEED, angle.sin() * BALL_SPEED);
-
-            // Generate a random color for each ball
-            let color = rgb(random_f32(), random_f32(), random_f32());
-            let color_change_speed = rgb(0.005, 0.005, 0.005);
-            let color_change_direction = rgb(
-                if random::<bool>() { 1.0 } else { -1.0 },
-                if random::<bool>() { 1.0 } else { -1.0 },
-                if random::<bool>() { 1.0 } else { -1.0 },
+    // Swap for `Palette::ocean()` to select the other built-in palette.
+    let palette = Palette::fire();
+
+    // Swap for `PhysicsMode::Frictionless` for the original perpetual-motion screensaver.
+    let physics = PhysicsMode::Settling;
+
+    // Swap for `GameMode::FreeBounce` for the screensaver; `GameMode::Pong` is the playable mode.
+    let mode = GameMode::Pong;
+
+    // Normal distributions give the scene a natural spread of fast/slow and
+    // big/small balls instead of every ball moving at identical speed.
+    let speed_dist = Normal::new(BALL_SPEED, BALL_SPEED_STD_DEV).unwrap();
+    let radius_dist = Normal::new(BALL_RADIUS, BALL_RADIUS_STD_DEV).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let (balls, paddles) = match mode {
+        GameMode::FreeBounce => {
+            let balls = (0..BALL_COUNT)
+                .map(|_| {
+                    let position = random_range2(-400.0, 400.0, -300.0, 300.0);
+                    let angle = random_range(0.0, 2.0 * PI);
+                    let speed = speed_dist.sample(&mut rng).max(0.1);
+                    let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
+                    let radius = radius_dist
+                        .sample(&mut rng)
+                        .clamp(BALL_RADIUS_MIN, BALL_RADIUS_MAX);
+                    let palette_phase = random_f32();
+
+                    Ball::new(
+                        position,
+                        velocity,
+                        radius,
+                        mass_for_radius(radius),
+                        palette_phase,
+                    )
+                })
+                .collect();
+
+            (balls, None)
+        }
+        GameMode::Pong => {
+            let puck = Ball::new(
+                pt2(0.0, 0.0),
+                Vec2::new(BALL_SPEED, 0.0),
+                BALL_RADIUS,
+                BALL_MASS,
+                0.0,
             );
+            let left = Paddle::new(-400.0 + PADDLE_MARGIN);
+            let right = Paddle::new(400.0 - PADDLE_MARGIN);
+
+            (vec![puck], Some((left, right)))
+        }
+    };
 
-            Ball::new(
-                position,
-                velocity,
-                color,
-                color_change_speed,
-                color_change_direction,
-            )
-        })
-        .collect();
+    Model {
+        mode,
+        balls,
+        palette,
+        physics,
+        paddles,
+        score: (0, 0),
+    }
+}
 
-    Model { balls }
+fn update(app: &App, model: &mut Model, _update: Update) {
+    let win_rect = app.window_rect();
+    match model.mode {
+        GameMode::FreeBounce => update_free_bounce(model, &win_rect),
+        GameMode::Pong => update_pong(app, model, &win_rect),
+    }
 }
 
-fn update(_app: &App, model: &mut Model, _update: Update) {
-    let win_rect = _app.window_rect();
+fn update_free_bounce(model: &mut Model, win_rect: &Rect) {
+    let palette = &model.palette;
+    let physics = model.physics;
     model.balls.par_iter_mut().for_each(|ball| {
-        ball.update(&win_rect);
+        ball.update(win_rect, palette, physics);
     });
 
-    // Sequentially check for collisions between balls
-    for i in 0..model.balls.len() {
-        let (left, right) = model.balls.split_at_mut(i + 1);
-        if let Some(ball_i) = left.last_mut() {
-            for ball_j in right.iter_mut() {
-                ball_i.collide(ball_j);
-            }
-        }
+    // Broadphase: bucket balls into a uniform grid so we only test pairs
+    // that share or neighbor a cell, instead of every pair in the scene.
+    let mut grid = SpatialHash::new();
+    for (index, ball) in model.balls.iter().enumerate() {
+        grid.insert(index, ball);
+    }
+
+    let mut candidate_pairs = Vec::new();
+    grid.for_each_candidate_pair(|i, j| candidate_pairs.push((i, j)));
+
+    for (i, j) in candidate_pairs {
+        let (left, right) = model.balls.split_at_mut(j);
+        left[i].collide(&mut right[0]);
     }
 }
 
+fn update_pong(app: &App, model: &mut Model, win_rect: &Rect) {
+    // The puck still cycles through the palette like a free-bouncing ball,
+    // so it isn't rendered as the black it's constructed with.
+    let palette = &model.palette;
+    let puck = &mut model.balls[0];
+    puck.palette_phase = (puck.palette_phase + PALETTE_PHASE_SPEED) % 1.0;
+    puck.color = palette.sample(puck.palette_phase);
+
+    let (left, right) = model
+        .paddles
+        .as_mut()
+        .expect("Pong mode always has paddles");
+
+    if app.keys.down.contains(&Key::W) {
+        left.move_by(PADDLE_SPEED, win_rect);
+    }
+    if app.keys.down.contains(&Key::S) {
+        left.move_by(-PADDLE_SPEED, win_rect);
+    }
+    if app.keys.down.contains(&Key::Up) {
+        right.move_by(PADDLE_SPEED, win_rect);
+    }
+    if app.keys.down.contains(&Key::Down) {
+        right.move_by(-PADDLE_SPEED, win_rect);
+    }
+
+    let puck = &mut model.balls[0];
+    puck.position += puck.velocity;
+
+    if puck.position.y - puck.radius < win_rect.bottom()
+        || puck.position.y + puck.radius > win_rect.top()
+    {
+        puck.velocity.y = -puck.velocity.y;
+    }
+
+    if puck.velocity.x < 0.0
+        && puck.position.x - puck.radius <= left.position.x + left.half_width
+        && (puck.position.y - left.position.y).abs() <= left.half_height + puck.radius
+    {
+        reflect_off_paddle(puck, left, 1.0);
+    } else if puck.velocity.x > 0.0
+        && puck.position.x + puck.radius >= right.position.x - right.half_width
+        && (puck.position.y - right.position.y).abs() <= right.half_height + puck.radius
+    {
+        reflect_off_paddle(puck, right, -1.0);
+    }
+
+    // Score when the puck passes an edge, then serve it toward the loser.
+    if puck.position.x < win_rect.left() {
+        model.score.1 += 1;
+        serve_puck(puck, -1.0);
+    } else if puck.position.x > win_rect.right() {
+        model.score.0 += 1;
+        serve_puck(puck, 1.0);
+    }
+}
+
+/// Reflects the puck off a paddle, biasing the angle by where on the paddle
+/// it hit: a hit near the top/bottom deflects more steeply, up to
+/// `MAX_FIRE_ANGLE`. `direction` is `1.0` off the left paddle, `-1.0` off
+/// the right paddle.
+fn reflect_off_paddle(puck: &mut Ball, paddle: &Paddle, direction: f32) {
+    let offset = ((puck.position.y - paddle.position.y) / paddle.half_height).clamp(-1.0, 1.0);
+    let angle = offset * MAX_FIRE_ANGLE;
+    let speed = puck.velocity.length().max(BALL_SPEED);
+
+    puck.velocity = Vec2::new(direction * angle.cos() * speed, angle.sin() * speed);
+    // Push the puck clear of the paddle so it doesn't re-trigger next frame.
+    puck.position.x = paddle.position.x + direction * (paddle.half_width + puck.radius);
+}
+
+/// Resets the puck to center and fires it toward whichever side just lost
+/// the point, at `direction` (`1.0` = toward the right, `-1.0` = toward the
+/// left).
+fn serve_puck(puck: &mut Ball, direction: f32) {
+    puck.position = pt2(0.0, 0.0);
+    let angle = random_range(-MAX_FIRE_ANGLE, MAX_FIRE_ANGLE);
+    puck.velocity = Vec2::new(
+        direction * angle.cos() * BALL_SPEED,
+        angle.sin() * BALL_SPEED,
+    );
+}
+
 fn view(app: &App, model: &Model, frame: Frame) {
     let draw = app.draw();
     draw.background().color(BLACK);
 
     for ball in &model.balls {
-        // Draw the glowing effect
-        draw.ellipse()
-            .x_y(ball.position.x, ball.position.y)
-            .radius(30.0) // Increase the radius to create a glow around the ball
-            .color(rgba(ball.color.red, ball.color.green, ball.color.blue, 0.2));
+        draw_glow(&draw, ball);
 
         // Draw the ball
         draw.ellipse()
             .x_y(ball.position.x, ball.position.y)
-            .radius(20.0)
+            .radius(ball.radius)
             .color(ball.color);
+
+        // A marker riding the rim makes the ball's spin visible.
+        let marker =
+            ball.position + Vec2::new(ball.spin_angle.cos(), ball.spin_angle.sin()) * ball.radius;
+        draw.ellipse()
+            .xy(marker)
+            .radius(ball.radius * 0.15)
+            .color(WHITE);
+    }
+
+    if let Some((left, right)) = &model.paddles {
+        for paddle in [left, right] {
+            draw.rect()
+                .xy(paddle.position)
+                .w_h(paddle.half_width * 2.0, paddle.half_height * 2.0)
+                .color(WHITE);
+        }
+
+        draw.text(&format!("{}   {}", model.score.0, model.score.1))
+            .x_y(0.0, app.window_rect().top() - 30.0)
+            .font_size(24)
+            .color(WHITE);
     }
 
     draw.to_frame(app, &frame).unwrap();
 }
 
+/// Approximates a radial falloff by layering `GLOW_RINGS` concentric discs
+/// from largest (most transparent) to smallest (most opaque). The whole
+/// glow is drawn with an additive blend instead of the default over
+/// operator, so overlapping glows literally sum and brighten where balls
+/// cluster rather than just occluding one another.
+fn draw_glow(draw: &Draw, ball: &Ball) {
+    let draw = draw.color_blend(BLEND_ADD);
+
+    for i in 0..GLOW_RINGS {
+        let t = i as f32 / GLOW_RINGS as f32;
+        let r = ball.glow_radius * (1.0 - t);
+        let falloff = (1.0 - r / ball.glow_radius).powi(2);
+        let alpha = ball.glow_intensity * falloff;
+
+        draw.ellipse()
+            .x_y(ball.position.x, ball.position.y)
+            .radius(r)
+            .color(rgba(
+                ball.color.red,
+                ball.color.green,
+                ball.color.blue,
+                alpha,
+            ));
+    }
+}
+
 fn random_range2(min_x: f32, max_x: f32, min_y: f32, max_y: f32) -> Point2 {
     let x = random_range(min_x, max_x);
     let y = random_range(min_y, max_y);
     pt2(x, y)
 }
-